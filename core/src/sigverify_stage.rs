@@ -13,9 +13,10 @@ use solana_sdk::timing;
 use solana_streamer::streamer::{self, PacketReceiver, StreamerError};
 use std::{
     collections::HashMap,
+    net::IpAddr,
     sync::mpsc::{Receiver, RecvTimeoutError},
     thread::{self, Builder, JoinHandle},
-    time::Instant,
+    time::{Duration, Instant},
 };
 use thiserror::Error;
 
@@ -43,6 +44,75 @@ pub trait SigVerifier {
 #[derive(Default, Clone)]
 pub struct DisabledSigVerifier {}
 
+struct TokenBucket {
+    tokens: u64,
+    last_refill: Instant,
+}
+
+/// Token bucket per source IP address. Each source may send up to
+/// `max_packets_per_sec` packets per second, with bursts of up to one second's worth.
+pub struct SourceRateLimiter {
+    max_packets_per_sec: u64,
+    buckets: HashMap<IpAddr, TokenBucket>,
+    last_purge: Instant,
+}
+
+impl SourceRateLimiter {
+    pub fn new(max_packets_per_sec: u64) -> Self {
+        Self {
+            max_packets_per_sec,
+            buckets: HashMap::new(),
+            last_purge: Instant::now(),
+        }
+    }
+
+    /// Marks packets from sources that have used up their budget as discarded and
+    /// returns how many were dropped. Packets already discarded are not charged.
+    pub fn discard_rate_limited(&mut self, batches: &mut [Packets], now: Instant) -> usize {
+        // A bucket left idle for a second is full again, the same as a new one
+        if now.saturating_duration_since(self.last_purge) > Duration::from_secs(1) {
+            self.buckets.retain(|_, bucket| {
+                now.saturating_duration_since(bucket.last_refill) <= Duration::from_secs(1)
+            });
+            self.last_purge = now;
+        }
+
+        let max_packets_per_sec = self.max_packets_per_sec;
+        let mut num_discarded = 0;
+        for packet in batches
+            .iter_mut()
+            .flat_map(|batch| batch.packets.iter_mut())
+            .filter(|packet| !packet.meta.discard)
+        {
+            let bucket = self
+                .buckets
+                .entry(packet.meta.addr().ip())
+                .or_insert(TokenBucket {
+                    tokens: max_packets_per_sec,
+                    last_refill: now,
+                });
+            let elapsed_us = now
+                .saturating_duration_since(bucket.last_refill)
+                .as_micros();
+            let refill = elapsed_us * u128::from(max_packets_per_sec) / 1_000_000;
+            // Only move last_refill forward once whole tokens are earned, so frequent
+            // calls don't round the refill down to nothing
+            if refill > 0 {
+                bucket.tokens = (u128::from(bucket.tokens) + refill)
+                    .min(u128::from(max_packets_per_sec)) as u64;
+                bucket.last_refill = now;
+            }
+            if bucket.tokens > 0 {
+                bucket.tokens -= 1;
+            } else {
+                packet.meta.discard = true;
+                num_discarded += 1;
+            }
+        }
+        num_discarded
+    }
+}
+
 #[derive(Default)]
 struct SigVerifierStats {
     recv_batches_us_hist: histogram::Histogram, // time to call recv_batch
@@ -51,6 +121,8 @@ struct SigVerifierStats {
     packets_hist: histogram::Histogram,         // number of packets per verify call
     total_batches: usize,
     total_packets: usize,
+    total_excess_discarded: usize,
+    total_rate_limited_discarded: usize,
 }
 
 impl SigVerifierStats {
@@ -115,6 +187,12 @@ impl SigVerifierStats {
             ("packets_mean", self.packets_hist.mean().unwrap_or(0), i64),
             ("total_batches", self.total_batches, i64),
             ("total_packets", self.total_packets, i64),
+            ("total_excess_discarded", self.total_excess_discarded, i64),
+            (
+                "total_rate_limited_discarded",
+                self.total_rate_limited_discarded,
+                i64
+            ),
         );
     }
 }
@@ -132,8 +210,14 @@ impl SigVerifyStage {
         packet_receiver: Receiver<Packets>,
         verified_sender: CrossbeamSender<Vec<Packets>>,
         verifier: T,
+        max_packets_per_source_per_sec: Option<u64>,
     ) -> Self {
-        let thread_hdl = Self::verifier_services(packet_receiver, verified_sender, verifier);
+        let thread_hdl = Self::verifier_services(
+            packet_receiver,
+            verified_sender,
+            verifier,
+            max_packets_per_source_per_sec,
+        );
         Self { thread_hdl }
     }
 
@@ -170,6 +254,7 @@ impl SigVerifyStage {
         recvr: &PacketReceiver,
         sendr: &CrossbeamSender<Vec<Packets>>,
         verifier: &T,
+        rate_limiter: &mut Option<SourceRateLimiter>,
         stats: &mut SigVerifierStats,
     ) -> Result<()> {
         let (mut batches, num_packets, recv_duration) = streamer::recv_batch(recvr)?;
@@ -180,9 +265,14 @@ impl SigVerifyStage {
             timing::timestamp(),
             num_packets,
         );
+        let mut excess_discarded = 0;
         if num_packets > MAX_SIGVERIFY_BATCH {
             Self::discard_excess_packets(&mut batches, MAX_SIGVERIFY_BATCH);
+            excess_discarded = num_packets - MAX_SIGVERIFY_BATCH;
         }
+        let rate_limited_discarded = rate_limiter.as_mut().map_or(0, |rate_limiter| {
+            rate_limiter.discard_rate_limited(&mut batches, Instant::now())
+        });
 
         let mut verify_batch_time = Measure::start("sigverify_batch_time");
         sendr.send(verifier.verify_batch(batches))?;
@@ -209,6 +299,8 @@ impl SigVerifyStage {
         stats.packets_hist.increment(num_packets as u64).unwrap();
         stats.total_batches += batches_len;
         stats.total_packets += num_packets;
+        stats.total_excess_discarded += excess_discarded;
+        stats.total_rate_limited_discarded += rate_limited_discarded;
 
         Ok(())
     }
@@ -217,16 +309,22 @@ impl SigVerifyStage {
         packet_receiver: PacketReceiver,
         verified_sender: CrossbeamSender<Vec<Packets>>,
         verifier: &T,
+        max_packets_per_source_per_sec: Option<u64>,
     ) -> JoinHandle<()> {
         let verifier = verifier.clone();
+        let mut rate_limiter = max_packets_per_source_per_sec.map(SourceRateLimiter::new);
         let mut stats = SigVerifierStats::default();
         let mut last_print = Instant::now();
         Builder::new()
             .name("solana-verifier".to_string())
             .spawn(move || loop {
-                if let Err(e) =
-                    Self::verifier(&packet_receiver, &verified_sender, &verifier, &mut stats)
-                {
+                if let Err(e) = Self::verifier(
+                    &packet_receiver,
+                    &verified_sender,
+                    &verifier,
+                    &mut rate_limiter,
+                    &mut stats,
+                ) {
                     match e {
                         SigVerifyServiceError::Streamer(StreamerError::RecvTimeout(
                             RecvTimeoutError::Disconnected,
@@ -253,8 +351,14 @@ impl SigVerifyStage {
         packet_receiver: PacketReceiver,
         verified_sender: CrossbeamSender<Vec<Packets>>,
         verifier: T,
+        max_packets_per_source_per_sec: Option<u64>,
     ) -> JoinHandle<()> {
-        Self::verifier_service(packet_receiver, verified_sender, &verifier)
+        Self::verifier_service(
+            packet_receiver,
+            verified_sender,
+            &verifier,
+            max_packets_per_source_per_sec,
+        )
     }
 
     pub fn join(self) -> thread::Result<()> {
@@ -265,7 +369,9 @@ impl SigVerifyStage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossbeam_channel::unbounded;
     use solana_perf::packet::Packet;
+    use std::sync::mpsc::channel;
 
     fn count_non_discard(packets: &[Packets]) -> usize {
         packets
@@ -292,4 +398,86 @@ mod tests {
         assert!(!packets[0].packets[0].meta.discard);
         assert!(!packets[0].packets[3].meta.discard);
     }
+
+    #[test]
+    fn test_excess_discarded_stats() {
+        let (packet_s, packet_r) = channel();
+        let (verified_s, verified_r) = unbounded();
+        let mut p = Packets::default();
+        p.packets.resize(MAX_SIGVERIFY_BATCH + 5, Packet::default());
+        packet_s.send(p).unwrap();
+
+        let mut stats = SigVerifierStats::default();
+        SigVerifyStage::verifier(
+            &packet_r,
+            &verified_s,
+            &DisabledSigVerifier::default(),
+            &mut None,
+            &mut stats,
+        )
+        .unwrap();
+        assert_eq!(stats.total_packets, MAX_SIGVERIFY_BATCH + 5);
+        assert_eq!(stats.total_excess_discarded, 5);
+        assert_eq!(stats.total_rate_limited_discarded, 0);
+        assert_eq!(verified_r.recv().unwrap().len(), 1);
+
+        // Packets left after the excess discard are charged to the source's budget
+        let mut p = Packets::default();
+        p.packets.resize(MAX_SIGVERIFY_BATCH + 5, Packet::default());
+        packet_s.send(p).unwrap();
+        let mut rate_limiter = Some(SourceRateLimiter::new(MAX_SIGVERIFY_BATCH as u64 - 1));
+        let mut stats = SigVerifierStats::default();
+        SigVerifyStage::verifier(
+            &packet_r,
+            &verified_s,
+            &DisabledSigVerifier::default(),
+            &mut rate_limiter,
+            &mut stats,
+        )
+        .unwrap();
+        assert_eq!(stats.total_excess_discarded, 5);
+        assert_eq!(stats.total_rate_limited_discarded, 1);
+    }
+
+    #[test]
+    fn test_source_rate_limiter() {
+        let max_packets_per_sec = 4;
+        let mut rate_limiter = SourceRateLimiter::new(max_packets_per_sec);
+        let now = Instant::now();
+
+        // Two sources share a batch, each gets its own budget
+        let mut p = Packets::default();
+        p.packets.resize(6, Packet::default());
+        for packet in p.packets.iter_mut().skip(3) {
+            packet.meta.addr = [1u16; 8];
+        }
+        p.packets[1].meta.discard = true;
+        let mut packets = vec![p];
+        assert_eq!(rate_limiter.discard_rate_limited(&mut packets, now), 0);
+        assert_eq!(count_non_discard(&packets), 5);
+
+        // The first source has two tokens left and the second has one
+        for packet in packets[0].packets.iter_mut() {
+            packet.meta.discard = false;
+        }
+        assert_eq!(rate_limiter.discard_rate_limited(&mut packets, now), 3);
+        assert_eq!(count_non_discard(&packets), 3);
+        assert!(packets[0].packets[2].meta.discard);
+        assert!(!packets[0].packets[3].meta.discard);
+
+        // Half a second refills half the budget
+        let now = now + Duration::from_millis(500);
+        let mut p = Packets::default();
+        p.packets.resize(3, Packet::default());
+        let mut packets = vec![p];
+        assert_eq!(rate_limiter.discard_rate_limited(&mut packets, now), 1);
+
+        // Idle sources are purged and start over with a full bucket
+        let now = now + Duration::from_secs(2);
+        let mut p = Packets::default();
+        p.packets.resize(1, Packet::default());
+        let mut packets = vec![p];
+        assert_eq!(rate_limiter.discard_rate_limited(&mut packets, now), 0);
+        assert_eq!(rate_limiter.buckets.len(), 1);
+    }
 }
@@ -70,6 +70,7 @@ impl Tpu {
         replay_vote_sender: ReplayVoteSender,
         bank_notification_sender: Option<BankNotificationSender>,
         tpu_coalesce_ms: u64,
+        tpu_max_packets_per_source_per_sec: Option<u64>,
         cluster_confirmed_slot_sender: GossipDuplicateConfirmedSlotsSender,
         cost_model: &Arc<RwLock<CostModel>>,
     ) -> Self {
@@ -86,7 +87,12 @@ impl Tpu {
 
         let sigverify_stage = {
             let verifier = TransactionSigVerifier::default();
-            SigVerifyStage::new(packet_receiver, verified_sender, verifier)
+            SigVerifyStage::new(
+                packet_receiver,
+                verified_sender,
+                verifier,
+                tpu_max_packets_per_source_per_sec,
+            )
         };
 
         let (verified_vote_packets_sender, verified_vote_packets_receiver) = unbounded();
@@ -165,6 +165,7 @@ impl Tvu {
             fetch_receiver,
             verified_sender,
             ShredSigVerifier::new(bank_forks.clone(), leader_schedule_cache.clone()),
+            None,
         );
 
         let cluster_slots = Arc::new(ClusterSlots::default());
@@ -158,6 +158,7 @@ pub struct ValidatorConfig {
     pub accounts_db_skip_shrink: bool,
     pub accounts_db_use_index_hash_calculation: bool,
     pub tpu_coalesce_ms: u64,
+    pub tpu_max_packets_per_source_per_sec: Option<u64>,
     pub validator_exit: Arc<RwLock<Exit>>,
     pub no_wait_for_vote_to_start_leader: bool,
     pub accounts_shrink_ratio: AccountShrinkThreshold,
@@ -217,6 +218,7 @@ impl Default for ValidatorConfig {
             accounts_db_skip_shrink: false,
             accounts_db_use_index_hash_calculation: true,
             tpu_coalesce_ms: DEFAULT_TPU_COALESCE_MS,
+            tpu_max_packets_per_source_per_sec: None,
             validator_exit: Arc::new(RwLock::new(Exit::default())),
             no_wait_for_vote_to_start_leader: true,
             accounts_shrink_ratio: AccountShrinkThreshold::default(),
@@ -849,6 +851,7 @@ impl Validator {
             replay_vote_sender,
             bank_notification_sender,
             config.tpu_coalesce_ms,
+            config.tpu_max_packets_per_source_per_sec,
             cluster_confirmed_slot_sender,
             &cost_model,
         );
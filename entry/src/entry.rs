@@ -29,7 +29,7 @@ use std::thread::JoinHandle;
 use std::time::Instant;
 use std::{cmp, thread};
 
-thread_local!(static PAR_THREAD_POOL: RefCell<ThreadPool> = RefCell::new(rayon::ThreadPoolBuilder::new()
+thread_local!(pub(crate) static PAR_THREAD_POOL: RefCell<ThreadPool> = RefCell::new(rayon::ThreadPoolBuilder::new()
                     .num_threads(get_thread_count())
                     .thread_name(|ix| format!("entry_{}", ix))
                     .build()
@@ -204,16 +204,27 @@ pub fn next_hash(
     num_hashes: u64,
     transactions: &[VersionedTransaction],
 ) -> Hash {
-    if num_hashes == 0 && transactions.is_empty() {
+    let mixin = if transactions.is_empty() {
+        None
+    } else {
+        Some(hash_transactions(transactions))
+    };
+    next_hash_with_mixin(start_hash, num_hashes, mixin)
+}
+
+/// Creates the hash `num_hashes` after `start_hash`, mixing `mixin` into the final
+/// hash if present and ticking otherwise. If num_hashes is zero and there is no
+/// mixin, start_hash is returned.
+pub fn next_hash_with_mixin(start_hash: &Hash, num_hashes: u64, mixin: Option<Hash>) -> Hash {
+    if num_hashes == 0 && mixin.is_none() {
         return *start_hash;
     }
 
     let mut poh = Poh::new(*start_hash, None);
     poh.hash(num_hashes.saturating_sub(1));
-    if transactions.is_empty() {
-        poh.tick().unwrap().hash
-    } else {
-        poh.record(hash_transactions(transactions)).unwrap().hash
+    match mixin {
+        None => poh.tick().unwrap().hash,
+        Some(mixin) => poh.record(mixin).unwrap().hash,
     }
 }
 
@@ -362,6 +373,9 @@ pub trait EntrySlice {
     fn start_verify(&self, start_hash: &Hash, recyclers: VerifyRecyclers)
         -> EntryVerificationState;
     fn verify(&self, start_hash: &Hash) -> bool;
+    /// Returns the index of the first entry whose hash does not follow from the entry
+    /// before it, or `None` if the whole slice is a valid chain from `start_hash`.
+    fn first_invalid_entry(&self, start_hash: &Hash) -> Option<usize>;
     /// Checks that each entry tick has the correct number of hashes. Entry slices do not
     /// necessarily end in a tick, so `tick_hash_count` is used to carry over the hash count
     /// for the next entry slice.
@@ -376,6 +390,20 @@ impl EntrySlice for [Entry] {
             .finish_verify()
     }
 
+    fn first_invalid_entry(&self, start_hash: &Hash) -> Option<usize> {
+        let genesis = [Entry {
+            num_hashes: 0,
+            hash: *start_hash,
+            transactions: vec![],
+        }];
+        let entry_pairs = genesis.par_iter().chain(self).zip(self);
+        PAR_THREAD_POOL.with(|thread_pool| {
+            thread_pool
+                .borrow()
+                .install(|| entry_pairs.position_first(|(x0, x1)| !x1.verify(&x0.hash)))
+        })
+    }
+
     fn verify_cpu_generic(&self, start_hash: &Hash) -> EntryVerificationState {
         let now = Instant::now();
         let genesis = [Entry {
@@ -845,6 +873,26 @@ mod tests {
         assert!(!bad_ticks.verify(&one)); // inductive step, bad
     }
 
+    #[test]
+    fn test_first_invalid_entry() {
+        solana_logger::setup();
+        let zero = Hash::default();
+        let one = hash(zero.as_ref());
+        assert_eq!(vec![][..].first_invalid_entry(&zero), None);
+
+        let mut ticks = vec![next_entry(&zero, 1, vec![])];
+        for _ in 0..4 {
+            ticks.push(next_entry(&ticks.last().unwrap().hash, 1, vec![]));
+        }
+        assert_eq!(ticks.first_invalid_entry(&zero), None);
+        assert_eq!(ticks.first_invalid_entry(&one), Some(0));
+
+        ticks[3].hash = one;
+        assert_eq!(ticks.first_invalid_entry(&zero), Some(3));
+        ticks[1].num_hashes += 1;
+        assert_eq!(ticks.first_invalid_entry(&zero), Some(1));
+    }
+
     #[test]
     fn test_verify_tick_hash_count() {
         let hashes_per_tick = 10;
@@ -0,0 +1,132 @@
+//! The `entry_header` module provides a compact form of an `Entry` that keeps the
+//! Proof of History chain verifiable without carrying the transactions themselves.
+//! Each header retains the merkle root of its entry's transaction signatures, which
+//! is exactly the value mixed into the hash chain, so a light client can check PoH
+//! continuity over a ledger from its headers alone.
+use crate::entry::{hash_transactions, next_hash_with_mixin, Entry, PAR_THREAD_POOL};
+use log::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash;
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct EntryHeader {
+    /// The number of hashes since the previous Entry ID.
+    pub num_hashes: u64,
+
+    /// The SHA-256 hash `num_hashes` after the previous Entry ID.
+    pub hash: Hash,
+
+    /// The merkle root of the entry's transaction signatures, or `None` for a tick.
+    pub transactions_root: Option<Hash>,
+}
+
+impl From<&Entry> for EntryHeader {
+    fn from(entry: &Entry) -> Self {
+        let transactions_root = if entry.is_tick() {
+            None
+        } else {
+            Some(hash_transactions(&entry.transactions))
+        };
+        Self {
+            num_hashes: entry.num_hashes,
+            hash: entry.hash,
+            transactions_root,
+        }
+    }
+}
+
+impl EntryHeader {
+    pub fn is_tick(&self) -> bool {
+        self.transactions_root.is_none()
+    }
+
+    /// Verifies self.hash is the result of hashing a `start_hash` `self.num_hashes` times,
+    /// mixing in the transactions root on the last hash if this is not a tick.
+    pub fn verify(&self, start_hash: &Hash) -> bool {
+        let ref_hash = next_hash_with_mixin(start_hash, self.num_hashes, self.transactions_root);
+        if self.hash != ref_hash {
+            warn!(
+                "entry header hash is invalid expected: {:?} actual: {:?}",
+                self.hash, ref_hash
+            );
+            return false;
+        }
+        true
+    }
+}
+
+/// Strips the transactions from `entries`, keeping only what is needed to verify the
+/// hash chain.
+pub fn entry_headers(entries: &[Entry]) -> Vec<EntryHeader> {
+    entries.iter().map(EntryHeader::from).collect()
+}
+
+/// Verifies that `headers` form a continuous hash chain starting at `start_hash`.
+pub fn verify_entry_headers(headers: &[EntryHeader], start_hash: &Hash) -> bool {
+    let genesis = [EntryHeader {
+        hash: *start_hash,
+        ..EntryHeader::default()
+    }];
+    let header_pairs = genesis.par_iter().chain(headers).zip(headers);
+    PAR_THREAD_POOL.with(|thread_pool| {
+        thread_pool
+            .borrow()
+            .install(|| header_pairs.all(|(h0, h1)| h1.verify(&h0.hash)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::{create_ticks, next_entry_mut, EntrySlice};
+    use solana_sdk::{
+        hash::hash,
+        signature::{Keypair, Signer},
+        system_transaction,
+    };
+
+    #[test]
+    fn test_entry_headers_verify() {
+        let zero = Hash::default();
+        let keypair = Keypair::new();
+        let mut entries = create_ticks(2, 4, zero);
+        let mut last_hash = entries.last().unwrap().hash;
+        let tx = system_transaction::transfer(&keypair, &keypair.pubkey(), 1, zero);
+        entries.push(next_entry_mut(&mut last_hash, 3, vec![tx]));
+        entries.push(next_entry_mut(&mut last_hash, 1, vec![]));
+        assert!(entries.verify(&zero));
+
+        let headers = entry_headers(&entries);
+        assert_eq!(headers.len(), entries.len());
+        assert!(headers[0].is_tick());
+        assert!(!headers[2].is_tick());
+        assert!(verify_entry_headers(&headers, &zero));
+        assert!(!verify_entry_headers(&headers, &last_hash));
+        assert!(verify_entry_headers(&[], &zero));
+    }
+
+    #[test]
+    fn test_entry_headers_tampered() {
+        let zero = Hash::default();
+        let keypair = Keypair::new();
+        let mut last_hash = zero;
+        let tx = system_transaction::transfer(&keypair, &keypair.pubkey(), 1, zero);
+        let entries = vec![
+            next_entry_mut(&mut last_hash, 2, vec![tx]),
+            next_entry_mut(&mut last_hash, 2, vec![]),
+        ];
+
+        let mut headers = entry_headers(&entries);
+        headers[0].transactions_root = Some(hash(&[1, 2, 3]));
+        assert!(!verify_entry_headers(&headers, &zero));
+
+        let mut headers = entry_headers(&entries);
+        headers[0].transactions_root = None;
+        assert!(!verify_entry_headers(&headers, &zero));
+
+        let mut headers = entry_headers(&entries);
+        headers[1].num_hashes += 1;
+        assert!(!verify_entry_headers(&headers, &zero));
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::integer_arithmetic)]
 pub mod entry;
+pub mod entry_header;
 pub mod poh;
 
 extern crate log;
@@ -14,6 +14,7 @@ use solana_clap_utils::{
         is_bin, is_parsable, is_pubkey, is_pubkey_or_keypair, is_slot, is_valid_percentage,
     },
 };
+use solana_cli_output::OutputFormat;
 use solana_core::cost_model::CostModel;
 use solana_core::cost_tracker::CostTracker;
 use solana_entry::entry::Entry;
@@ -54,6 +55,7 @@ use solana_sdk::{
     transaction::{SanitizedTransaction, TransactionError},
 };
 use solana_stake_program::stake_state::{self, PointValue};
+use solana_transaction_status::{EncodedTransaction, UiTransactionEncoding};
 use solana_vote_program::{
     self,
     vote_state::{self, VoteState},
@@ -76,6 +78,56 @@ use bigtable::*;
 enum LedgerOutputMethod {
     Print,
     Json,
+    JsonCompact,
+}
+
+// Items in the JSON ledger array are preceded by a separator rather than followed by
+// one, so the array never ends with a trailing comma
+fn output_json_item<T: Serialize>(method: &LedgerOutputMethod, first: bool, item: &T) {
+    if !first {
+        stdout().write_all(b",\n").expect("separator");
+    }
+    match method {
+        LedgerOutputMethod::Json => serde_json::to_writer_pretty(stdout(), item),
+        LedgerOutputMethod::JsonCompact => serde_json::to_writer(stdout(), item),
+        LedgerOutputMethod::Print => unreachable!(),
+    }
+    .expect("serialize");
+}
+
+// JSON rendering of an `Entry`, with the hash in base58 and transactions in the same
+// encoding as RPC responses
+#[derive(Serialize)]
+struct CliEntry {
+    num_hashes: u64,
+    hash: String,
+    transactions: Vec<EncodedTransaction>,
+}
+
+impl CliEntry {
+    fn new(slot: Slot, entry: Entry) -> Self {
+        let transactions = entry
+            .transactions
+            .into_iter()
+            .filter_map(|transaction| {
+                let tx_signature = transaction.signatures[0];
+                let legacy_tx = transaction.into_legacy_transaction();
+                if legacy_tx.is_none() {
+                    eprintln!(
+                        "Failed to encode unsupported transaction for {} at slot {}",
+                        tx_signature, slot
+                    );
+                }
+                legacy_tx
+            })
+            .map(|legacy_tx| EncodedTransaction::encode(legacy_tx, UiTransactionEncoding::Json))
+            .collect();
+        Self {
+            num_hashes: entry.num_hashes,
+            hash: entry.hash.to_string(),
+            transactions,
+        }
+    }
 }
 
 fn output_slot_rewards(blockstore: &Blockstore, slot: Slot, method: &LedgerOutputMethod) {
@@ -158,10 +210,10 @@ fn output_entry(
                 }
             }
         }
-        LedgerOutputMethod::Json => {
-            // Note: transaction status is not output in JSON yet
-            serde_json::to_writer(stdout(), &entry).expect("serialize entry");
-            stdout().write_all(b",\n").expect("newline");
+        LedgerOutputMethod::Json | LedgerOutputMethod::JsonCompact => {
+            // Note: transaction status is not output in JSON yet.
+            // An entry always follows its slot meta, so it is never the first item
+            output_json_item(method, false, &CliEntry::new(slot, entry));
         }
     }
 }
@@ -208,7 +260,7 @@ fn output_slot(
         }
 
         output_slot_rewards(blockstore, slot, method);
-    } else if verbose_level >= 1 {
+    } else if verbose_level >= 1 && *method == LedgerOutputMethod::Print {
         let mut transactions = 0;
         let mut hashes = 0;
         let mut program_ids = HashMap::new();
@@ -273,7 +325,7 @@ fn output_ledger(
             exit(1);
         });
 
-    if method == LedgerOutputMethod::Json {
+    if method != LedgerOutputMethod::Print {
         stdout().write_all(b"{\"ledger\":[\n").expect("open array");
     }
 
@@ -291,9 +343,8 @@ fn output_ledger(
             LedgerOutputMethod::Print => {
                 println!("Slot {} root?: {}", slot, blockstore.is_root(slot))
             }
-            LedgerOutputMethod::Json => {
-                serde_json::to_writer(stdout(), &slot_meta).expect("serialize slot_meta");
-                stdout().write_all(b",\n").expect("newline");
+            LedgerOutputMethod::Json | LedgerOutputMethod::JsonCompact => {
+                output_json_item(&method, num_printed == 0, &slot_meta);
             }
         }
 
@@ -306,7 +357,7 @@ fn output_ledger(
         }
     }
 
-    if method == LedgerOutputMethod::Json {
+    if method != LedgerOutputMethod::Print {
         stdout().write_all(b"\n]}\n").expect("close array");
     }
 }
@@ -1036,7 +1087,7 @@ fn main() {
                 .takes_value(true)
                 .possible_values(&["json", "json-compact"])
                 .help("Return information in specified output format, \
-                       currently only available for bigtable subcommands"),
+                       currently only available for bigtable and print subcommands"),
         )
         .arg(
             Arg::with_name("verbose")
@@ -1615,6 +1666,13 @@ fn main() {
             let num_slots = value_t!(arg_matches, "num_slots", Slot).ok();
             let allow_dead_slots = arg_matches.is_present("allow_dead_slots");
             let only_rooted = arg_matches.is_present("only_rooted");
+            let output_format =
+                OutputFormat::from_matches(arg_matches, "output_format", verbose_level > 0);
+            let method = match output_format {
+                OutputFormat::Json => LedgerOutputMethod::Json,
+                OutputFormat::JsonCompact => LedgerOutputMethod::JsonCompact,
+                _ => LedgerOutputMethod::Print,
+            };
             output_ledger(
                 open_blockstore(
                     &ledger_path,
@@ -1624,7 +1682,7 @@ fn main() {
                 starting_slot,
                 ending_slot,
                 allow_dead_slots,
-                LedgerOutputMethod::Print,
+                method,
                 num_slots,
                 verbose_level,
                 only_rooted,
@@ -1822,7 +1880,7 @@ fn main() {
                 starting_slot,
                 Slot::MAX,
                 allow_dead_slots,
-                LedgerOutputMethod::Json,
+                LedgerOutputMethod::JsonCompact,
                 None,
                 std::u64::MAX,
                 true,
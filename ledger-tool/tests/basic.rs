@@ -47,3 +47,25 @@ fn nominal() {
     assert!(output.status.success());
     assert_eq!(count_newlines(&output.stdout), ticks + meta_lines);
 }
+
+#[test]
+fn print_json() {
+    let genesis_config = create_genesis_config(100).genesis_config;
+    let ticks_per_slot = genesis_config.ticks_per_slot as usize;
+
+    let (ledger_path, _blockhash) = create_new_tmp_ledger!(&genesis_config);
+    let ledger_path = ledger_path.to_str().unwrap();
+
+    for &format in &["json", "json-compact"] {
+        let output = run_ledger_tool(&["-l", ledger_path, "print", "-vvv", "--output", format]);
+        assert!(output.status.success());
+
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let ledger = value["ledger"].as_array().unwrap();
+        // The slot meta followed by its entries
+        assert_eq!(ledger.len(), ticks_per_slot + 1);
+        let hash = ledger[1]["hash"].as_str().unwrap();
+        assert_eq!(bs58::decode(hash).into_vec().unwrap().len(), 32);
+        assert!(ledger[1]["transactions"].as_array().unwrap().is_empty());
+    }
+}
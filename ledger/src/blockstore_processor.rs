@@ -454,6 +454,31 @@ pub enum BlockstoreProcessorError {
 /// Callback for accessing bank state while processing the blockstore
 pub type ProcessCallback = Arc<dyn Fn(&Bank) + Sync + Send>;
 
+/// Replay progress, reported after each slot replayed from the blockstore and once
+/// more when replay stops
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessProgress {
+    /// Last slot replayed, or the starting root if no slot has been replayed
+    pub slot: Slot,
+    /// Latest root reached by replay
+    pub last_root: Slot,
+    /// Highest root known to the blockstore when replay started
+    pub max_root: Slot,
+    /// Slots replayed so far
+    pub num_slots: u64,
+    /// Transactions replayed so far
+    pub num_txs: usize,
+    /// Time since replay started
+    pub elapsed: Duration,
+    /// Whether this is the final report
+    pub finished: bool,
+}
+
+/// Callback for observing replay progress while processing the blockstore. Returning
+/// `false` after a slot stops replay there, as `dev_halt_at_slot` does; the return
+/// value of the final report is ignored.
+pub type ProgressCallback = Arc<dyn Fn(&ProcessProgress) -> bool + Sync + Send>;
+
 #[derive(Default, Clone)]
 pub struct ProcessOptions {
     pub bpf_jit: bool,
@@ -461,6 +486,7 @@ pub struct ProcessOptions {
     pub full_leader_cache: bool,
     pub dev_halt_at_slot: Option<Slot>,
     pub entry_callback: Option<ProcessCallback>,
+    pub progress_callback: Option<ProgressCallback>,
     pub override_num_threads: Option<usize>,
     pub new_hard_forks: Option<Vec<Slot>>,
     pub frozen_accounts: Vec<Pubkey>,
@@ -1069,12 +1095,16 @@ fn load_frozen_forks(
 ) -> result::Result<Vec<Arc<Bank>>, BlockstoreProcessorError> {
     let mut initial_forks = HashMap::new();
     let mut all_banks = HashMap::new();
+    let replay_start = Instant::now();
     let mut last_status_report = Instant::now();
     let mut last_free = Instant::now();
     let mut pending_slots = vec![];
     let mut last_root = root_bank.slot();
+    let mut last_slot = root_bank.slot();
     let mut slots_elapsed = 0;
     let mut txs = 0;
+    let mut num_slots = 0;
+    let mut num_txs = 0;
     let blockstore_max_root = blockstore.max_root();
     let max_root = std::cmp::max(root_bank.slot(), blockstore_max_root);
     info!(
@@ -1097,7 +1127,8 @@ fn load_frozen_forks(
             let (meta, bank, last_entry_hash) = pending_slots.pop().unwrap();
             let slot = bank.slot();
             if last_status_report.elapsed() > Duration::from_secs(2) {
-                let secs = last_status_report.elapsed().as_secs() as f32;
+                let elapsed = last_status_report.elapsed();
+                let secs = elapsed.as_secs() as f32;
                 last_status_report = Instant::now();
                 info!(
                     "processing ledger: slot={}, last root slot={} slots={} slots/s={:?} txs/s={}",
@@ -1129,6 +1160,7 @@ fn load_frozen_forks(
                 continue;
             }
             txs += progress.num_txs;
+            num_txs += progress.num_txs;
 
             // Block must be frozen by this point, otherwise `process_single_slot` would
             // have errored above
@@ -1240,6 +1272,8 @@ fn load_frozen_forks(
             }
 
             slots_elapsed += 1;
+            num_slots += 1;
+            last_slot = slot;
 
             trace!(
                 "Bank for {}slot {} is complete",
@@ -1256,12 +1290,43 @@ fn load_frozen_forks(
                 &mut initial_forks,
             )?;
 
+            let proceed = opts.progress_callback.as_ref().map_or(true, |callback| {
+                callback(&ProcessProgress {
+                    slot,
+                    last_root,
+                    max_root,
+                    num_slots,
+                    num_txs,
+                    elapsed: replay_start.elapsed(),
+                    finished: false,
+                })
+            });
+            if !proceed {
+                info!(
+                    "processing ledger: stopped by progress callback at slot {}",
+                    slot
+                );
+                break;
+            }
+
             if slot >= dev_halt_at_slot {
                 break;
             }
         }
     }
 
+    if let Some(progress_callback) = &opts.progress_callback {
+        progress_callback(&ProcessProgress {
+            slot: last_slot,
+            last_root,
+            max_root,
+            num_slots,
+            num_txs,
+            elapsed: replay_start.elapsed(),
+            finished: true,
+        });
+    }
+
     Ok(initial_forks.values().cloned().collect::<Vec<_>>())
 }
 
@@ -1497,7 +1562,11 @@ pub mod tests {
     };
     use std::{
         collections::BTreeSet,
-        sync::{mpsc::channel, RwLock},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc::channel,
+            RwLock,
+        },
     };
     use tempfile::TempDir;
     use trees::tr;
@@ -1837,6 +1906,57 @@ pub mod tests {
         assert_eq!(bank_forks.root(), 4);
     }
 
+    #[test]
+    fn test_process_blockstore_with_progress_callback() {
+        solana_logger::setup();
+
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+        let ticks_per_slot = genesis_config.ticks_per_slot;
+
+        let (ledger_path, blockhash) = create_new_tmp_ledger_auto_delete!(&genesis_config);
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+
+        // Slots 1 and 2 chain off of slot 0
+        let last_entry_hash =
+            fill_blockstore_slot_with_ticks(&blockstore, ticks_per_slot, 1, 0, blockhash);
+        fill_blockstore_slot_with_ticks(&blockstore, ticks_per_slot, 2, 1, last_entry_hash);
+
+        // Replay everything, then stop once slot 1 has been replayed
+        for &(stop_at_slot, num_slots) in &[(Slot::MAX, 2), (1, 1)] {
+            let num_reports = Arc::new(AtomicUsize::new(0));
+            let last_progress = Arc::new(RwLock::new(None));
+            let progress_callback: ProgressCallback = {
+                let num_reports = num_reports.clone();
+                let last_progress = last_progress.clone();
+                Arc::new(move |progress: &ProcessProgress| {
+                    num_reports.fetch_add(1, Ordering::Relaxed);
+                    *last_progress.write().unwrap() = Some(*progress);
+                    progress.slot < stop_at_slot
+                })
+            };
+            let opts = ProcessOptions {
+                poh_verify: true,
+                progress_callback: Some(progress_callback),
+                ..ProcessOptions::default()
+            };
+            let (bank_forks, ..) = test_process_blockstore(&genesis_config, &blockstore, opts);
+
+            // Only replayed, frozen banks are kept
+            let expected_slots: Vec<_> = (0..=num_slots).collect();
+            assert_eq!(frozen_bank_slots(&bank_forks), expected_slots);
+            assert!(bank_forks.banks().values().all(|bank| bank.is_frozen()));
+
+            // One report per replayed slot, then the final report
+            assert_eq!(num_reports.load(Ordering::Relaxed), num_slots as usize + 1);
+            let progress = last_progress.read().unwrap().unwrap();
+            assert!(progress.finished);
+            assert_eq!(progress.slot, num_slots);
+            assert_eq!(progress.last_root, 0);
+            assert_eq!(progress.num_slots, num_slots);
+            assert_eq!(progress.num_txs, 0);
+        }
+    }
+
     #[test]
     fn test_process_blockstore_with_two_forks() {
         solana_logger::setup();
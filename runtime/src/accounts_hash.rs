@@ -195,6 +195,17 @@ impl CumulativeOffsets {
     }
 }
 
+/// Proof that a hash is a leaf of a merkle root computed by
+/// `compute_merkle_root_recurse()` over the same hashes in the same order.
+/// Each level, starting from the leaves, holds the chunk of (up to `fanout`) hashes
+/// containing the proven hash or its ancestor.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Index of the proven hash among the leaves
+    pub index: usize,
+    pub levels: Vec<Vec<Hash>>,
+}
+
 #[derive(Debug)]
 pub struct AccountsHash {
     pub dummy: i32,
@@ -254,13 +265,7 @@ impl AccountsHash {
                 let start_index = i * fanout;
                 let end_index = std::cmp::min(start_index + fanout, total_hashes);
 
-                let mut hasher = Hasher::default();
-                for item in hashes.iter().take(end_index).skip(start_index) {
-                    let h = extractor(item);
-                    hasher.hash(h.as_ref());
-                }
-
-                hasher.result()
+                Self::hash_chunk(&hashes[start_index..end_index], &extractor)
             })
             .collect();
         time.stop();
@@ -273,6 +278,105 @@ impl AccountsHash {
         }
     }
 
+    fn hash_chunk<T, F>(chunk: &[T], extractor: F) -> Hash
+    where
+        F: Fn(&T) -> Hash,
+    {
+        let mut hasher = Hasher::default();
+        for item in chunk {
+            hasher.hash(extractor(item).as_ref());
+        }
+        hasher.result()
+    }
+
+    /// Builds the proof that `hashes[index]` is included in
+    /// `compute_merkle_root_recurse(hashes, fanout)`. To prove an account against the
+    /// delta hash from `accumulate_account_hashes()`, `hashes` must be in the same
+    /// pubkey-sorted order, see `sort_hashes_by_pubkey()`.
+    pub fn compute_merkle_proof(
+        mut hashes: Vec<Hash>,
+        fanout: usize,
+        mut index: usize,
+    ) -> Option<MerkleProof> {
+        if index >= hashes.len() {
+            return None;
+        }
+
+        let leaf_index = index;
+        let mut levels = vec![];
+        loop {
+            let start_index = index / fanout * fanout;
+            let end_index = std::cmp::min(start_index + fanout, hashes.len());
+            levels.push(hashes[start_index..end_index].to_vec());
+            if hashes.len() <= fanout {
+                break;
+            }
+            hashes = hashes
+                .chunks(fanout)
+                .map(|chunk| Self::hash_chunk(chunk, |hash: &Hash| *hash))
+                .collect();
+            index /= fanout;
+        }
+        Some(MerkleProof {
+            index: leaf_index,
+            levels,
+        })
+    }
+
+    /// Builds the proof that the account hash of `pubkey` is included in
+    /// `accumulate_account_hashes(hashes)`, where `hashes` holds the (pubkey, account
+    /// hash) pairs the root is computed over, as for the accounts delta hash. Returns
+    /// the account hash along with its proof.
+    pub fn compute_account_merkle_proof(
+        mut hashes: Vec<(Pubkey, Hash)>,
+        pubkey: &Pubkey,
+    ) -> Option<(Hash, MerkleProof)> {
+        Self::sort_hashes_by_pubkey(&mut hashes);
+        let index = hashes.binary_search_by(|(key, _)| key.cmp(pubkey)).ok()?;
+        let hash = hashes[index].1;
+        let hashes = hashes.into_iter().map(|(_, hash)| hash).collect();
+        Self::compute_merkle_proof(hashes, MERKLE_FANOUT, index).map(|proof| (hash, proof))
+    }
+
+    /// Checks a proof produced by `compute_merkle_proof()` for `hash` against `root`.
+    /// `num_leaves` is the number of hashes `root` was computed over and must come from
+    /// the same trusted source as `root`. The tree does not separate leaves from
+    /// interior nodes, so the leaf count is what fixes the proof's depth and the shape
+    /// of each level.
+    pub fn verify_merkle_proof(
+        root: &Hash,
+        hash: &Hash,
+        proof: &MerkleProof,
+        num_leaves: usize,
+        fanout: usize,
+    ) -> bool {
+        let mut count = num_leaves;
+        let mut index = proof.index;
+        if index >= count {
+            return false;
+        }
+        let mut levels = proof.levels.iter();
+        let mut current = *hash;
+        loop {
+            let chunk = match levels.next() {
+                Some(chunk) => chunk,
+                None => return false,
+            };
+            let start_index = index / fanout * fanout;
+            let end_index = std::cmp::min(start_index + fanout, count);
+            if chunk.len() != end_index - start_index || chunk[index - start_index] != current {
+                return false;
+            }
+            current = Self::hash_chunk(chunk, |hash: &Hash| *hash);
+            if count <= fanout {
+                break;
+            }
+            count = Self::div_ceil(count, fanout);
+            index /= fanout;
+        }
+        levels.next().is_none() && current == *root
+    }
+
     fn calculate_three_level_chunks(
         total_hashes: usize,
         fanout: usize,
@@ -1789,6 +1893,143 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_accountsdb_merkle_proof() {
+        for &fanout in &[2, 3, MERKLE_FANOUT] {
+            let hash_counts = [
+                1,
+                2,
+                fanout,
+                fanout + 1,
+                fanout * fanout,
+                fanout * fanout + 1,
+            ];
+            for &hash_count in &hash_counts {
+                let hashes: Vec<_> = (0..hash_count).map(|_| Hash::new_unique()).collect();
+                let root = AccountsHash::compute_merkle_root_recurse(hashes.clone(), fanout);
+                for (index, hash) in hashes.iter().enumerate() {
+                    let proof =
+                        AccountsHash::compute_merkle_proof(hashes.clone(), fanout, index).unwrap();
+                    assert_eq!(proof.index, index);
+                    assert!(AccountsHash::verify_merkle_proof(
+                        &root, hash, &proof, hash_count, fanout
+                    ));
+                    assert!(!AccountsHash::verify_merkle_proof(
+                        &root,
+                        &Hash::new_unique(),
+                        &proof,
+                        hash_count,
+                        fanout
+                    ));
+                    assert!(!AccountsHash::verify_merkle_proof(
+                        &Hash::new_unique(),
+                        hash,
+                        &proof,
+                        hash_count,
+                        fanout
+                    ));
+                    assert!(!AccountsHash::verify_merkle_proof(
+                        &root,
+                        hash,
+                        &proof,
+                        hash_count + 1,
+                        fanout
+                    ));
+                }
+                assert_eq!(
+                    AccountsHash::compute_merkle_proof(hashes, fanout, hash_count),
+                    None
+                );
+            }
+        }
+        assert!(!AccountsHash::verify_merkle_proof(
+            &Hash::default(),
+            &Hash::default(),
+            &MerkleProof::default(),
+            1,
+            MERKLE_FANOUT
+        ));
+    }
+
+    #[test]
+    fn test_accountsdb_merkle_proof_truncated() {
+        let fanout = 3;
+        let hash_count = fanout * fanout + 1;
+        let hashes: Vec<_> = (0..hash_count).map(|_| Hash::new_unique()).collect();
+        let root = AccountsHash::compute_merkle_root_recurse(hashes.clone(), fanout);
+        let proof = AccountsHash::compute_merkle_proof(hashes, fanout, 4).unwrap();
+        assert_eq!(proof.levels.len(), 3);
+
+        // Dropping the leaf level turns the proof into one for an interior hash
+        let interior_hash = AccountsHash::hash_chunk(&proof.levels[0], |hash: &Hash| *hash);
+        let truncated = MerkleProof {
+            index: proof.index / fanout,
+            levels: proof.levels[1..].to_vec(),
+        };
+        assert!(!AccountsHash::verify_merkle_proof(
+            &root,
+            &interior_hash,
+            &truncated,
+            hash_count,
+            fanout
+        ));
+
+        // Extra or misplaced levels are rejected as well
+        let mut extended = proof.clone();
+        extended.levels.push(vec![root]);
+        assert!(!AccountsHash::verify_merkle_proof(
+            &root,
+            &proof.levels[0][1],
+            &extended,
+            hash_count,
+            fanout
+        ));
+        let mut moved = proof.clone();
+        moved.index += fanout;
+        assert!(!AccountsHash::verify_merkle_proof(
+            &root,
+            &proof.levels[0][1],
+            &moved,
+            hash_count,
+            fanout
+        ));
+    }
+
+    #[test]
+    fn test_accountsdb_account_merkle_proof() {
+        let hash_count = MERKLE_FANOUT + 3;
+        let hashes: Vec<_> = (0..hash_count)
+            .map(|_| (Pubkey::new_unique(), Hash::new_unique()))
+            .collect();
+        let root = AccountsHash::accumulate_account_hashes(hashes.clone());
+        for (pubkey, account_hash) in &hashes {
+            let (hash, proof) =
+                AccountsHash::compute_account_merkle_proof(hashes.clone(), pubkey).unwrap();
+            assert_eq!(hash, *account_hash);
+            assert!(AccountsHash::verify_merkle_proof(
+                &root,
+                &hash,
+                &proof,
+                hash_count,
+                MERKLE_FANOUT
+            ));
+
+            let proof: MerkleProof =
+                bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap();
+            assert!(AccountsHash::verify_merkle_proof(
+                &root,
+                &hash,
+                &proof,
+                hash_count,
+                MERKLE_FANOUT
+            ));
+        }
+        assert_eq!(
+            AccountsHash::compute_account_merkle_proof(hashes, &Pubkey::new_unique()),
+            None
+        );
+    }
+
     #[test]
     #[should_panic(expected = "overflow is detected while summing capitalization")]
     fn test_accountsdb_lamport_overflow() {
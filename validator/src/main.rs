@@ -1037,6 +1037,15 @@ pub fn main() {
                 .validator(is_parsable::<u64>)
                 .help("Milliseconds to wait in the TPU receiver for packet coalescing."),
         )
+        .arg(
+            Arg::with_name("tpu_max_packets_per_source_per_sec")
+                .long("tpu-max-packets-per-source-per-sec")
+                .value_name("PACKETS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .help("Drop TPU packets from any source IP address sending more than this \
+                       many packets per second [default: no limit]"),
+        )
         .arg(
             Arg::with_name("rocksdb_max_compaction_jitter")
                 .long("rocksdb-max-compaction-jitter-slots")
@@ -2068,6 +2077,12 @@ pub fn main() {
         accounts_db_skip_shrink: matches.is_present("accounts_db_skip_shrink"),
         accounts_db_use_index_hash_calculation: matches.is_present("accounts_db_index_hashing"),
         tpu_coalesce_ms,
+        tpu_max_packets_per_source_per_sec: value_t!(
+            matches,
+            "tpu_max_packets_per_source_per_sec",
+            u64
+        )
+        .ok(),
         no_wait_for_vote_to_start_leader: matches.is_present("no_wait_for_vote_to_start_leader"),
         accounts_shrink_ratio,
         ..ValidatorConfig::default()